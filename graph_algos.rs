@@ -1,17 +1,59 @@
 use rand::seq::SliceRandom;
 use rand::thread_rng;
-use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
 
 
 //                         Breadth First Search
 // ------------------------------------------------------------------------------------------------
 
 // DistancePair represents two developers from Github and the shortest
-// distance between them in the graph.
+// distance between them in the graph, along with the actual chain of
+// developers connecting them (empty if node_2 is unreachable from node_1).
 pub struct DistancePair {
     pub node_1: u32,
     pub node_2: u32,
     pub distance: u32,
+    pub path: Vec<u32>,
+}
+
+// Walks a predecessor map backward from `end` to `start`, reversing the
+// result so it reads start -> ... -> end.  Returns an empty path if `end`
+// is unreachable from `start` (no predecessor chain leads back to `start`).
+pub fn reconstruct_path(preds: &HashMap<u32, u32>, start: u32, end: u32) -> Vec<u32> {
+    if start == end {
+        return vec![start];
+    }
+
+    let mut path = vec![end];
+    let mut curr = end;
+    while curr != start {
+        match preds.get(&curr) {
+            Some(&pred) => {
+                curr = pred;
+                path.push(curr);
+            }
+            None => return vec![],
+        }
+    }
+
+    path.reverse();
+    path
+}
+
+// Confirms reconstruct_path handles the three cases it needs to: the
+// trivial start == end path, a normal multi-hop chain, and an end node
+// with no predecessor chain back to start (unreachable).
+#[test]
+fn test_reconstruct_path() {
+    assert_eq!(reconstruct_path(&HashMap::new(), 1, 1), vec![1]);
+
+    let mut preds: HashMap<u32, u32> = HashMap::new();
+    preds.insert(2, 1);
+    preds.insert(3, 2);
+    assert_eq!(reconstruct_path(&preds, 1, 3), vec![1, 2, 3]);
+
+    assert_eq!(reconstruct_path(&preds, 1, 99), Vec::<u32>::new());
 }
 
 // This function will randomly generate a collection of vertices of size num_vertices
@@ -37,7 +79,7 @@ pub fn run_random_test_bfs(adjacency_list: &HashMap<u32, Vec<u32>>, num_vertices
     // outer loop proceeds over all nodes
     for i in 0..num_vertices {
         let start_node = chosen_vertices[i];
-        let dists = breadth_first_search(adjacency_list, start_node);
+        let (dists, preds) = breadth_first_search(adjacency_list, start_node);
         // inner loop proceeds for all nodes in the list after location i.
         for j in (i + 1)..num_vertices {
             let end_node = chosen_vertices[j];
@@ -47,6 +89,7 @@ pub fn run_random_test_bfs(adjacency_list: &HashMap<u32, Vec<u32>>, num_vertices
                 node_1: start_node,
                 node_2: end_node,
                 distance: dist,
+                path: reconstruct_path(&preds, start_node, end_node),
             };
             test_shortest_distances.push(current_pair);
         }
@@ -71,9 +114,12 @@ fn init_hashmap(adjacency_list: &HashMap<u32, Vec<u32>>, start_node: u32) -> Has
 
 
 // Standard BFS graph search algorithm.  Starts with a particular node and returns a Map
-// holding the shortest distance to all other nodes in the graph.
-pub fn breadth_first_search(adjacency_list: &HashMap<u32, Vec<u32>>, start_node: u32) -> HashMap<u32, u32> {
+// holding the shortest distance to all other nodes in the graph, along with a
+// predecessor map recording the node each vertex was first reached from so
+// the actual shortest path (not just its length) can be reconstructed.
+pub fn breadth_first_search(adjacency_list: &HashMap<u32, Vec<u32>>, start_node: u32) -> (HashMap<u32, u32>, HashMap<u32, u32>) {
     let mut dists = init_hashmap(adjacency_list, start_node);
+    let mut preds: HashMap<u32, u32> = HashMap::new();
 
     let mut queue: VecDeque<u32> = VecDeque::new();
     queue.push_back(start_node);
@@ -84,69 +130,387 @@ pub fn breadth_first_search(adjacency_list: &HashMap<u32, Vec<u32>>, start_node:
             let new_dist = dists[&curr_node] + 1;
             if new_dist < dists[neighbor] {
                 dists.insert(*neighbor, new_dist);
+                preds.insert(*neighbor, curr_node);
                 queue.push_back(*neighbor);
             }
         }
     }
 
-    dists
+    (dists, preds)
+}
+
+
+// Expands one full level (BFS layer) of a frontier, adding newly discovered
+// neighbors to `visited`/`queue`.  If any neighbor is already present in the
+// other side's visited set, the two frontiers have met and the combined
+// distance is returned.
+fn expand_bidir_frontier(
+    adjacency_list: &HashMap<u32, Vec<u32>>,
+    queue: &mut VecDeque<u32>,
+    visited: &mut HashMap<u32, u32>,
+    other_visited: &HashMap<u32, u32>,
+) -> Option<u32> {
+    let level_size = queue.len();
+    for _ in 0..level_size {
+        let curr_node = queue.pop_front().unwrap();
+        let curr_dist = visited[&curr_node];
+        for &neighbor in adjacency_list.get(&curr_node).unwrap_or(&vec![]) {
+            if let std::collections::hash_map::Entry::Vacant(entry) = visited.entry(neighbor) {
+                entry.insert(curr_dist + 1);
+                queue.push_back(neighbor);
+            }
+            if let Some(&other_dist) = other_visited.get(&neighbor) {
+                return Some(visited[&neighbor] + other_dist);
+            }
+        }
+    }
+    None
+}
+
+// Bidirectional BFS: expands a frontier from `start` and a frontier from
+// `goal` at the same time instead of running a full single-source BFS, and
+// stops as soon as the two frontiers meet.  Since the graph is undirected
+// this halves the effective search radius for a single pairwise query.  The
+// smaller of the two frontiers is always expanded next to keep the combined
+// work low.
+pub fn bidirectional_bfs(adjacency_list: &HashMap<u32, Vec<u32>>, start: u32, goal: u32) -> Option<u32> {
+    if start == goal {
+        return Some(0);
+    }
+
+    let mut forward_visited: HashMap<u32, u32> = HashMap::new();
+    let mut backward_visited: HashMap<u32, u32> = HashMap::new();
+    forward_visited.insert(start, 0);
+    backward_visited.insert(goal, 0);
+
+    let mut forward_queue: VecDeque<u32> = VecDeque::new();
+    forward_queue.push_back(start);
+    let mut backward_queue: VecDeque<u32> = VecDeque::new();
+    backward_queue.push_back(goal);
+
+    while !forward_queue.is_empty() && !backward_queue.is_empty() {
+        let meeting_dist = if forward_queue.len() <= backward_queue.len() {
+            expand_bidir_frontier(adjacency_list, &mut forward_queue, &mut forward_visited, &backward_visited)
+        } else {
+            expand_bidir_frontier(adjacency_list, &mut backward_queue, &mut backward_visited, &forward_visited)
+        };
+
+        if meeting_dist.is_some() {
+            return meeting_dist;
+        }
+    }
+
+    None
+}
+
+// Confirms bidirectional BFS finds the correct distance on a 5 node path
+// graph (exercising the equal-frontier-size tie in the expansion order,
+// since both sides start with a single-node frontier), the trivial
+// start == goal case, and returns None for a genuinely disconnected pair.
+#[test]
+fn test_bidirectional_bfs() {
+    let mut adjacency_list: HashMap<u32, Vec<u32>> = HashMap::new();
+    adjacency_list.insert(1, vec![2]);
+    adjacency_list.insert(2, vec![1, 3]);
+    adjacency_list.insert(3, vec![2, 4]);
+    adjacency_list.insert(4, vec![3, 5]);
+    adjacency_list.insert(5, vec![4]);
+    adjacency_list.insert(6, vec![]);
+
+    assert_eq!(bidirectional_bfs(&adjacency_list, 1, 5), Some(4));
+    assert_eq!(bidirectional_bfs(&adjacency_list, 3, 3), Some(0));
+    assert_eq!(bidirectional_bfs(&adjacency_list, 1, 6), None);
+}
+
+// This function will randomly generate a collection of vertices of size num_vertices
+// and find the shortest distance between all pairs of that collection using
+// bidirectional BFS instead of a full single-source BFS per start node, so
+// the statistics pipeline can compare its runtime against plain BFS and
+// Dijkstra in the main driver.  Bidirectional BFS only recovers the
+// distance between a pair, not the path between them, so `path` is always
+// empty on the returned pairs.
+pub fn run_random_test_bidir(adjacency_list: &HashMap<u32, Vec<u32>>, num_vertices: usize) -> Vec<DistancePair> {
+    assert!(
+        num_vertices <= adjacency_list.len(),
+        "Requested sample is bigger than graph"
+    );
+
+    let mut bidir_shortest_dists: Vec<DistancePair> = Vec::new();
+
+    let mut rng = thread_rng();
+    let mut vertices: Vec<u32> = adjacency_list.keys().cloned().collect();
+    vertices.shuffle(&mut rng);
+    let chosen_vertices = &vertices[..num_vertices];
+
+    for i in 0..num_vertices {
+        let start_node = chosen_vertices[i];
+        for j in (i + 1)..num_vertices {
+            let end_node = chosen_vertices[j];
+            let dist = bidirectional_bfs(adjacency_list, start_node, end_node).unwrap_or(u32::MAX);
+            let current_pair = DistancePair {
+                node_1: start_node,
+                node_2: end_node,
+                distance: dist,
+                path: vec![],
+            };
+            bidir_shortest_dists.push(current_pair);
+        }
+    }
+
+    bidir_shortest_dists
+}
+
+
+//                         Centrality Measures
+// ------------------------------------------------------------------------------------------------
+
+// CentralityScore represents a single node's closeness and betweenness
+// centrality within the graph, used to rank developers by how central
+// they are to the network.
+pub struct CentralityScore {
+    pub node: u32,
+    pub closeness: f64,
+    pub betweenness: f64,
 }
 
+// Randomly samples `num_nodes` distinct node ids from the graph.  Brandes'
+// betweenness centrality is O(V * (V + E)), so running it from every node
+// in a graph the size of musae_git would take hours; sampling the source
+// nodes down to the same size the other driver sections already use keeps
+// the ranking bounded while still estimating centrality from real BFS runs
+// over the full graph structure.
+pub fn sample_nodes(adjacency_list: &HashMap<u32, Vec<u32>>, num_nodes: usize) -> Vec<u32> {
+    assert!(
+        num_nodes <= adjacency_list.len(),
+        "Requested sample is bigger than graph"
+    );
+
+    let mut rng = thread_rng();
+    let mut vertices: Vec<u32> = adjacency_list.keys().cloned().collect();
+    vertices.shuffle(&mut rng);
+    vertices.truncate(num_nodes);
+    vertices
+}
+
+// Computes closeness centrality for every node in `sources`: the reciprocal
+// of the sum of shortest distances from that node to every other reachable
+// node.  Nodes that are unreachable from a given source do not contribute
+// to its sum (their distance is u32::MAX).  Isolated nodes (sum of zero)
+// are given a closeness of 0.0.
+fn closeness_centrality(adjacency_list: &HashMap<u32, Vec<u32>>, sources: &[u32]) -> HashMap<u32, f64> {
+    let mut closeness: HashMap<u32, f64> = HashMap::new();
+
+    for &node in sources {
+        let (dists, _preds) = breadth_first_search(adjacency_list, node);
+        let sum_of_dists: u64 = dists
+            .values()
+            .filter(|&&d| d != u32::MAX)
+            .map(|&d| d as u64)
+            .sum();
+
+        let score = if sum_of_dists == 0 { 0.0 } else { 1.0 / sum_of_dists as f64 };
+        closeness.insert(node, score);
+    }
+
+    closeness
+}
+
+// Computes betweenness centrality using Brandes' algorithm, accumulated only
+// over the given `sources` instead of every node in the graph so the cost
+// stays bounded on large graphs (see `sample_nodes`).  For each source node
+// s, a BFS records the distance, the number of shortest paths (sigma), and
+// the predecessors on a shortest path (preds) for every reachable node.
+// Dependencies are then accumulated back along the BFS order (decreasing
+// distance from s) to build up each node's betweenness score.  Scores are
+// halved at the end since the graph is undirected: when `sources` holds
+// every node, each pair is processed from both endpoints.  With a sample of
+// sources the halved scores are an estimate, not exact betweenness.
+fn betweenness_centrality(adjacency_list: &HashMap<u32, Vec<u32>>, sources: &[u32]) -> HashMap<u32, f64> {
+    let mut betweenness: HashMap<u32, f64> = adjacency_list.keys().map(|&node| (node, 0.0)).collect();
+
+    for &s in sources {
+        let mut stack: Vec<u32> = Vec::new();
+        let mut preds: HashMap<u32, Vec<u32>> = adjacency_list.keys().map(|&node| (node, Vec::new())).collect();
+        let mut sigma: HashMap<u32, f64> = adjacency_list.keys().map(|&node| (node, 0.0)).collect();
+        let mut dist: HashMap<u32, u32> = adjacency_list.keys().map(|&node| (node, u32::MAX)).collect();
+
+        sigma.insert(s, 1.0);
+        dist.insert(s, 0);
+
+        let mut queue: VecDeque<u32> = VecDeque::new();
+        queue.push_back(s);
+
+        while let Some(v) = queue.pop_front() {
+            stack.push(v);
+            for &w in adjacency_list.get(&v).unwrap_or(&vec![]) {
+                // w found for the first time?
+                if dist[&w] == u32::MAX {
+                    dist.insert(w, dist[&v] + 1);
+                    queue.push_back(w);
+                }
+                // shortest path to w via v?
+                if dist[&w] == dist[&v] + 1 {
+                    let sigma_v = sigma[&v];
+                    *sigma.get_mut(&w).unwrap() += sigma_v;
+                    preds.get_mut(&w).unwrap().push(v);
+                }
+            }
+        }
+
+        let mut delta: HashMap<u32, f64> = adjacency_list.keys().map(|&node| (node, 0.0)).collect();
+
+        // process vertices in order of decreasing distance from s
+        while let Some(w) = stack.pop() {
+            let delta_w = delta[&w];
+            for &v in &preds[&w] {
+                let contribution = (sigma[&v] / sigma[&w]) * (1.0 + delta_w);
+                *delta.get_mut(&v).unwrap() += contribution;
+            }
+            if w != s {
+                *betweenness.get_mut(&w).unwrap() += delta_w;
+            }
+        }
+    }
+
+    // undirected graph: with every node as a source, each shortest path is
+    // counted once from each endpoint
+    for score in betweenness.values_mut() {
+        *score /= 2.0;
+    }
+
+    betweenness
+}
 
+// Ranks the given sample of nodes by closeness and betweenness centrality,
+// returning a vector sorted by descending betweenness so the most
+// influential "brokers" in the network appear first.  Betweenness is
+// estimated from BFS runs rooted at just these `sources` rather than every
+// node in the graph (see `sample_nodes`), the same sampling trade-off the
+// rest of the driver already makes for BFS/Dijkstra/bidirectional BFS.
+pub fn rank_by_centrality(adjacency_list: &HashMap<u32, Vec<u32>>, sources: &[u32]) -> Vec<CentralityScore> {
+    let closeness = closeness_centrality(adjacency_list, sources);
+    let betweenness = betweenness_centrality(adjacency_list, sources);
+
+    let mut scores: Vec<CentralityScore> = sources
+        .iter()
+        .map(|&node| CentralityScore {
+            node,
+            closeness: closeness[&node],
+            betweenness: betweenness[&node],
+        })
+        .collect();
+
+    scores.sort_by(|a, b| b.betweenness.partial_cmp(&a.betweenness).unwrap());
+    scores
+}
 
 
 //         Dijkstra's Algorithm
 //
 // ------------------------------------------------------------------------------------------------
 
-// NodeCost is used in the p-queue.
+// NodeCost is used in the p-queue.  It is generic over the node type N so the
+// same priority queue plumbing can drive searches over any node
+// representation, not just u32 ids.  The u32 is the priority the heap orders
+// by: the plain g-cost for Dijkstra, or g + h(node) once a heuristic is
+// supplied (see `search`).
 #[derive(Eq, PartialEq)]
-struct NodeCost(u32, u32);
+struct NodeCost<N>(N, u32);
 
 // Dijkstra's algorithm uses a min-ordered priority queue.  This means that it needs to pop out
 // the smallest value from the priority queue.  Since we are using a binary heap, we need to reverse the
 // ordering so that it will be a min-heap since a regular binary heap is a max-heap.
-impl Ord for NodeCost {
+impl<N: Eq> Ord for NodeCost<N> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.1.cmp(&other.1).reverse()
     }
 }
 
-impl PartialOrd for NodeCost {
+impl<N: Eq> PartialOrd for NodeCost<N> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-
-// Implementation of Dijkstra's algorithm - a single-source shortest path algorithm.
-// My implementation uses a Binary Heap for the priority queue with values reversed so it is a
-// min-heap.
-fn dijkstras(adj_list: &HashMap<u32, Vec<(u32, u32)>>, start: u32) -> HashMap<u32, u32> {
-
-    //create a hashmap of distances for all nodes in the graph.  Will be updated by
-    //the algorithm as we discover new nodes
-    let mut dists: HashMap<u32, u32> = adj_list.keys().map(|node| (*node, u32::MAX)).collect();
-
-    // a Binary Heap is used for the priority queue
+// Generic A*/Dijkstra search engine.  `neighbor_fn` supplies the (neighbor,
+// edge weight) pairs reachable from a given node, and `goal_fn` tells the
+// engine when to stop early instead of relaxing the whole graph.  Passing a
+// heuristic `h` that returns 0 for every node and a `goal_fn` that never
+// matches degenerates to plain single-source Dijkstra, which is how
+// `dijkstras` below is implemented in terms of this engine.
+//
+// The heap is ordered by f = g + h(node) (pure Dijkstra when h is always 0),
+// while the true g-costs are tracked separately in `g_costs` so the returned
+// map holds real path costs rather than heuristic-inflated ones.  A popped
+// entry is skipped if it no longer matches the best known f-cost for that
+// node, the same stale-entry guard the original Dijkstra implementation used.
+// Alongside the g-costs, a predecessor map is built up so the actual
+// shortest path (not just its length) can be recovered with
+// `reconstruct_path`.
+pub fn search<N, F, G, H>(start: N, neighbor_fn: F, goal_fn: G, h: H) -> (HashMap<N, u32>, HashMap<N, N>)
+where
+    N: Clone + Eq + Hash,
+    F: Fn(&N) -> Vec<(N, u32)>,
+    G: Fn(&N) -> bool,
+    H: Fn(&N) -> u32,
+{
+    let mut g_costs: HashMap<N, u32> = HashMap::new();
+    let mut preds: HashMap<N, N> = HashMap::new();
     let mut p_queue = BinaryHeap::new();
 
-    dists.insert(start, 0);
-    p_queue.push(NodeCost(start, 0));
+    g_costs.insert(start.clone(), 0);
+    p_queue.push(NodeCost(start.clone(), h(&start)));
 
-    while let Some(NodeCost(curr_node, curr_cost)) = p_queue.pop() {
-        if curr_cost > dists[&curr_node] {
+    while let Some(NodeCost(curr_node, curr_f)) = p_queue.pop() {
+        let curr_g = g_costs[&curr_node];
+        if curr_f > curr_g + h(&curr_node) {
             continue;
         }
-        for (neighbor, weight) in adj_list.get(&curr_node).unwrap_or(&vec![]) {
-            let new_cost = curr_cost + weight;
-            if new_cost < dists[neighbor] {
-                dists.insert(*neighbor, new_cost);
-                p_queue.push(NodeCost(*neighbor, new_cost));
+        if goal_fn(&curr_node) {
+            break;
+        }
+        for (neighbor, weight) in neighbor_fn(&curr_node) {
+            let new_g = curr_g + weight;
+            if new_g < *g_costs.get(&neighbor).unwrap_or(&u32::MAX) {
+                g_costs.insert(neighbor.clone(), new_g);
+                preds.insert(neighbor.clone(), curr_node.clone());
+                p_queue.push(NodeCost(neighbor.clone(), new_g + h(&neighbor)));
             }
         }
     }
-    dists
+    (g_costs, preds)
+}
+
+// Confirms the generic `search` engine works as plain A* over a non-u32 node
+// type: a straight line of (x, 0) grid cells, each step costing 1, guided by
+// a Manhattan-distance heuristic to the goal and a goal_fn that stops the
+// search as soon as it's reached instead of relaxing the whole grid.
+#[test]
+fn test_search_generic_astar_over_grid_nodes() {
+    let goal = (3, 0);
+    let (g_costs, preds) = search(
+        (0, 0),
+        |&(x, y): &(i32, i32)| vec![((x + 1, y), 1), ((x - 1, y), 1)],
+        |&node| node == goal,
+        |&(x, y)| ((goal.0 - x).abs() + (goal.1 - y).abs()) as u32,
+    );
+
+    assert_eq!(g_costs[&goal], 3);
+    assert_eq!(preds[&goal], (2, 0));
+    assert_eq!(preds[&(2, 0)], (1, 0));
+    assert_eq!(preds[&(1, 0)], (0, 0));
+}
+
+// Implementation of Dijkstra's algorithm - a single-source shortest path algorithm.
+// Delegates to the generic `search` engine with a zero heuristic and a goal
+// that never fires, so every reachable node gets relaxed just like before.
+fn dijkstras(adj_list: &HashMap<u32, Vec<(u32, u32)>>, start: u32) -> (HashMap<u32, u32>, HashMap<u32, u32>) {
+    search(
+        start,
+        |node| adj_list.get(node).cloned().unwrap_or_default(),
+        |_node| false,
+        |_node| 0,
+    )
 }
 
 // This function will randomly generate a collection of vertices of size num_vertices
@@ -175,16 +539,27 @@ pub fn run_random_test_dijkstras(
     // outer loop proceeds over all nodes
     for i in 0..num_vertices {
         let start_node = chosen_vertices[i];
-        let dists = dijkstras(adjacency_list, start_node);
         // inner loop proceeds for all nodes in the list after location i.
         for j in (i + 1)..num_vertices {
             let end_node = chosen_vertices[j];
-            let dist = dists[&end_node] as u32;
+            // search for just this pair, stopping as soon as end_node is
+            // reached instead of relaxing the whole graph from start_node.
+            let (dists, preds) = search(
+                start_node,
+                |node| adjacency_list.get(node).cloned().unwrap_or_default(),
+                |node| *node == end_node,
+                |_node| 0,
+            );
+            // unlike breadth_first_search, search() only inserts an entry
+            // once a node is actually relaxed, so an unreachable end_node
+            // has no key here and must fall back to u32::MAX.
+            let dist = dists.get(&end_node).copied().unwrap_or(u32::MAX);
             // shortest_dists.insert((start_node, end_node), dist);
             let current_pair = DistancePair {
                 node_1: start_node,
                 node_2: end_node,
                 distance: dist,
+                path: reconstruct_path(&preds, start_node, end_node),
             };
             dijkstras_shortest_dists.push(current_pair);
         }
@@ -192,3 +567,304 @@ pub fn run_random_test_dijkstras(
 
     dijkstras_shortest_dists
 }
+
+// Confirms run_random_test_dijkstras reports u32::MAX for an unreachable
+// pair instead of panicking.  Unlike breadth_first_search, the generic
+// search() engine backing this function only inserts a distance entry for
+// nodes it actually relaxes, so node 3 (disconnected from 1 and 2) has no
+// entry in `dists` and must fall back correctly rather than index-panic.
+#[test]
+fn test_run_random_test_dijkstras_unreachable_node() {
+    let mut adjacency_list: HashMap<u32, Vec<(u32, u32)>> = HashMap::new();
+    adjacency_list.insert(1, vec![(2, 1)]);
+    adjacency_list.insert(2, vec![(1, 1)]);
+    adjacency_list.insert(3, vec![]);
+
+    let dists = run_random_test_dijkstras(&adjacency_list, 3);
+
+    let unreachable_pair = dists
+        .iter()
+        .find(|d| d.node_1 == 3 || d.node_2 == 3)
+        .expect("node 3 should still appear in the sampled pairs");
+    assert_eq!(unreachable_pair.distance, u32::MAX);
+}
+
+
+//         Yen's Algorithm (k shortest loopless paths)
+// ------------------------------------------------------------------------------------------------
+
+// PathCandidate is used in the candidate p-queue below.  Like NodeCost it is
+// ordered by its cost (reversed so BinaryHeap behaves as a min-heap), with
+// the path itself carried along as the payload.
+#[derive(Eq, PartialEq)]
+struct PathCandidate(Vec<u32>, u32);
+
+impl Ord for PathCandidate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.1.cmp(&other.1).reverse()
+    }
+}
+
+impl PartialOrd for PathCandidate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Sums the edge weights along a path of nodes.  Assumes every consecutive
+// pair in `path` is actually an edge in `adj_list`.
+fn path_cost(adj_list: &HashMap<u32, Vec<(u32, u32)>>, path: &[u32]) -> u32 {
+    path.windows(2)
+        .map(|pair| {
+            adj_list
+                .get(&pair[0])
+                .and_then(|neighbors| neighbors.iter().find(|(node, _)| *node == pair[1]))
+                .map(|(_, weight)| *weight)
+                .unwrap_or(0)
+        })
+        .sum()
+}
+
+// Finds up to `k` distinct loopless shortest paths from `source` to `target`
+// using Yen's algorithm.  The first path A[0] comes straight from the
+// generic `search` engine; each subsequent A[i] is found by, for every
+// "spur node" along A[i-1], temporarily removing the edges and root-path
+// nodes shared with previously found paths and re-running `search` from the
+// spur node to `target`.  The cheapest candidate produced across all spur
+// nodes becomes A[i].  Returns fewer than k paths if the graph doesn't have
+// that many distinct routes between source and target.
+pub fn k_shortest_paths(
+    adj_list: &HashMap<u32, Vec<(u32, u32)>>,
+    source: u32,
+    target: u32,
+    k: usize,
+) -> Vec<Vec<u32>> {
+    let (dists, preds) = search(
+        source,
+        |node| adj_list.get(node).cloned().unwrap_or_default(),
+        |node| *node == target,
+        |_node| 0,
+    );
+    if dists.get(&target).copied().unwrap_or(u32::MAX) == u32::MAX {
+        return vec![];
+    }
+
+    let mut found_paths: Vec<Vec<u32>> = vec![reconstruct_path(&preds, source, target)];
+    let mut candidates: BinaryHeap<PathCandidate> = BinaryHeap::new();
+    let mut candidate_paths: HashSet<Vec<u32>> = HashSet::new();
+
+    while found_paths.len() < k {
+        let prev_path = found_paths.last().unwrap().clone();
+
+        for spur_index in 0..prev_path.len().saturating_sub(1) {
+            let spur_node = prev_path[spur_index];
+            let root_path = &prev_path[..=spur_index];
+
+            // remove edges that coincide with this root path in any
+            // previously found path sharing the same root.
+            let mut removed_edges: HashSet<(u32, u32)> = HashSet::new();
+            for path in &found_paths {
+                if path.len() > spur_index && path[..=spur_index] == *root_path {
+                    removed_edges.insert((path[spur_index], path[spur_index + 1]));
+                    removed_edges.insert((path[spur_index + 1], path[spur_index]));
+                }
+            }
+
+            // remove the root path's nodes (except the spur node itself) so
+            // the spur search can't loop back through them.
+            let removed_nodes: HashSet<u32> = root_path[..spur_index].iter().cloned().collect();
+
+            let (spur_dists, spur_preds) = search(
+                spur_node,
+                |node| {
+                    adj_list
+                        .get(node)
+                        .cloned()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|(neighbor, _)| {
+                            !removed_nodes.contains(neighbor) && !removed_edges.contains(&(*node, *neighbor))
+                        })
+                        .collect()
+                },
+                |node| *node == target,
+                |_node| 0,
+            );
+
+            if spur_dists.get(&target).copied().unwrap_or(u32::MAX) == u32::MAX {
+                continue;
+            }
+
+            let spur_path = reconstruct_path(&spur_preds, spur_node, target);
+            let mut total_path = root_path[..spur_index].to_vec();
+            total_path.extend(spur_path);
+
+            if !found_paths.contains(&total_path) && candidate_paths.insert(total_path.clone()) {
+                let cost = path_cost(adj_list, &total_path);
+                candidates.push(PathCandidate(total_path, cost));
+            }
+        }
+
+        match candidates.pop() {
+            Some(PathCandidate(path, _)) => {
+                candidate_paths.remove(&path);
+                found_paths.push(path);
+            }
+            None => break,
+        }
+    }
+
+    found_paths
+}
+
+// Confirms Yen's algorithm finds both loopless shortest routes through a
+// diamond graph 1 -> {2, 3} -> 4, each costing 2, and stops once the graph
+// is exhausted instead of fabricating a non-existent third route.
+#[test]
+fn test_k_shortest_paths_diamond_graph() {
+    let mut adj_list: HashMap<u32, Vec<(u32, u32)>> = HashMap::new();
+    adj_list.insert(1, vec![(2, 1), (3, 1)]);
+    adj_list.insert(2, vec![(1, 1), (4, 1)]);
+    adj_list.insert(3, vec![(1, 1), (4, 1)]);
+    adj_list.insert(4, vec![(2, 1), (3, 1)]);
+
+    let paths = k_shortest_paths(&adj_list, 1, 4, 3);
+
+    assert_eq!(paths.len(), 2, "Diamond graph only has 2 loopless routes between 1 and 4");
+    for path in &paths {
+        assert_eq!(path_cost(&adj_list, path), 2);
+        assert_eq!((path[0], path[path.len() - 1]), (1, 4));
+    }
+}
+
+
+//         Incremental Dijkstra (dynamic edge insertions)
+// ------------------------------------------------------------------------------------------------
+
+// DynamicShortestPaths keeps the result of a single-source Dijkstra run
+// alive across edge insertions, so "what-if" edits (e.g. a new GitHub
+// follow) can update distances cheaply instead of rerunning Dijkstra's
+// algorithm over the whole graph from scratch.
+pub struct DynamicShortestPaths {
+    source: u32,
+    dists: HashMap<u32, u32>,
+    adj_list: HashMap<u32, Vec<(u32, u32)>>,
+}
+
+impl DynamicShortestPaths {
+    // Runs an initial Dijkstra search from `source` over `adj_list` and
+    // keeps the resulting distances around to be updated incrementally.
+    pub fn new(adj_list: HashMap<u32, Vec<(u32, u32)>>, source: u32) -> Self {
+        let (dists, _preds) = dijkstras(&adj_list, source);
+        DynamicShortestPaths {
+            source,
+            dists,
+            adj_list,
+        }
+    }
+
+    // Adds an edge (u, v) of weight w to the graph and updates `dists` to
+    // reflect it.  Distances never increase on an edge insertion, so rather
+    // than re-relaxing the whole graph, this only seeds the priority queue
+    // with the endpoint whose distance actually improved and propagates
+    // that improvement outward, stopping as soon as it stops shrinking
+    // distances.
+    pub fn add_edge(&mut self, u: u32, v: u32, w: u32) {
+        self.adj_list.entry(u).or_default().push((v, w));
+        self.adj_list.entry(v).or_default().push((u, w));
+
+        self.dists.entry(u).or_insert(u32::MAX);
+        self.dists.entry(v).or_insert(u32::MAX);
+
+        let dist_u = self.dists[&u];
+        let dist_v = self.dists[&v];
+
+        let mut p_queue: BinaryHeap<NodeCost<u32>> = BinaryHeap::new();
+
+        if dist_u != u32::MAX && dist_u + w < dist_v {
+            self.dists.insert(v, dist_u + w);
+            p_queue.push(NodeCost(v, dist_u + w));
+        } else if dist_v != u32::MAX && dist_v + w < dist_u {
+            self.dists.insert(u, dist_v + w);
+            p_queue.push(NodeCost(u, dist_v + w));
+        }
+
+        // bounded relaxation: only nodes whose distance actually shrinks get
+        // pushed back onto the queue, so this only touches the affected
+        // subtree rather than the whole graph.
+        while let Some(NodeCost(curr_node, curr_cost)) = p_queue.pop() {
+            if curr_cost > self.dists[&curr_node] {
+                continue;
+            }
+            for (neighbor, weight) in self.adj_list.get(&curr_node).unwrap_or(&vec![]) {
+                let new_cost = curr_cost + weight;
+                if new_cost < *self.dists.get(neighbor).unwrap_or(&u32::MAX) {
+                    self.dists.insert(*neighbor, new_cost);
+                    p_queue.push(NodeCost(*neighbor, new_cost));
+                }
+            }
+        }
+    }
+
+    // Returns the source node this instance was built from.
+    pub fn source(&self) -> u32 {
+        self.source
+    }
+
+    // Returns the current distances, refreshed after every `add_edge` call.
+    pub fn dists(&self) -> &HashMap<u32, u32> {
+        &self.dists
+    }
+}
+
+// Confirms that inserting new edges both reaches previously
+// unreachable nodes and shortens an existing route, without having to
+// rerun Dijkstra's algorithm over the whole graph.
+#[test]
+fn test_dynamic_shortest_paths_add_edge() {
+    let mut adj_list: HashMap<u32, Vec<(u32, u32)>> = HashMap::new();
+    adj_list.insert(1, vec![(2, 5)]);
+    adj_list.insert(2, vec![(1, 5)]);
+
+    let mut dynamic_paths = DynamicShortestPaths::new(adj_list, 1);
+    assert_eq!(dynamic_paths.source(), 1);
+    assert_eq!(dynamic_paths.dists()[&2], 5);
+    // node 3 doesn't exist in the graph yet, so it's unreachable.
+    assert_eq!(dynamic_paths.dists().get(&3), None);
+
+    // a new edge from node 2 connects node 3 to the graph for the first time...
+    dynamic_paths.add_edge(2, 3, 1);
+    assert_eq!(dynamic_paths.dists()[&3], 6);
+
+    // ...and a direct shortcut between 1 and 2 shortens the existing route.
+    dynamic_paths.add_edge(1, 2, 1);
+    assert_eq!(dynamic_paths.dists()[&2], 1);
+    assert_eq!(dynamic_paths.dists()[&3], 2);
+}
+
+// Confirms Brandes' betweenness centrality against the hand-calculated
+// values for a 5 node path graph 1 - 2 - 3 - 4 - 5, where the betweenness
+// of node i is (i - 1) * (n - i): the endpoints contribute nothing, the
+// middle node (3) sits inside the most shortest paths and so scores highest.
+#[test]
+fn test_betweenness_centrality_path_graph() {
+    let mut adjacency_list: HashMap<u32, Vec<u32>> = HashMap::new();
+    adjacency_list.insert(1, vec![2]);
+    adjacency_list.insert(2, vec![1, 3]);
+    adjacency_list.insert(3, vec![2, 4]);
+    adjacency_list.insert(4, vec![3, 5]);
+    adjacency_list.insert(5, vec![4]);
+
+    let sources: Vec<u32> = vec![1, 2, 3, 4, 5];
+    let betweenness = betweenness_centrality(&adjacency_list, &sources);
+
+    assert!((betweenness[&1] - 0.0).abs() < 0.001);
+    assert!((betweenness[&2] - 3.0).abs() < 0.001);
+    assert!((betweenness[&3] - 4.0).abs() < 0.001);
+    assert!((betweenness[&4] - 3.0).abs() < 0.001);
+    assert!((betweenness[&5] - 0.0).abs() < 0.001);
+
+    // rank_by_centrality should surface the middle node as the top broker.
+    let rankings = rank_by_centrality(&adjacency_list, &sources);
+    assert_eq!(rankings[0].node, 3);
+}
@@ -4,7 +4,10 @@ mod graph_reader;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{Result,Write};
-use crate::graph_algos::{run_random_test_bfs, DistancePair, run_random_test_dijkstras};
+use crate::graph_algos::{
+    run_random_test_bfs, DistancePair, run_random_test_dijkstras, run_random_test_bidir,
+    rank_by_centrality, CentralityScore, k_shortest_paths, DynamicShortestPaths,
+};
 
 //for measuring runtime.
 use std::time::{Duration, Instant};
@@ -93,6 +96,95 @@ fn main() -> Result<()> {
                        dijkstras_std_dev)
         .expect("Couldn\'t write Dijkstras output.");
 
+    //  ------ Bidirectional BFS ------
+
+    println!("\n\n--> Bidirectional BFS Implementation\n");
+
+    //run random test using bidirectional BFS.  Same dataset will be used as for BFS and Dijkstra above.
+    let bidir_start = Instant::now();
+    let bidir_shortest_dists = run_random_test_bidir(&adjacency_list, count);
+    let bidir_end = Instant::now();
+    let bidir_elapsed = bidir_end - bidir_start;
+
+    let (bidir_num_distances,
+         bidir_mean_distance,
+         bidir_std_dev) = calc_distance_stats(&bidir_shortest_dists);
+    println!("Total pairs: {}", bidir_num_distances);
+    println!("Mean Distance: {:.2}", bidir_mean_distance);
+    println!("Standard Deviation: {:.3}", bidir_std_dev);
+    println!("Elapsed Time: {:?}", bidir_elapsed);
+
+    let output_filename = "BidirectionalBFS.txt";
+    let algoname = "Bidirectional BFS Algorithm";
+    create_output_file(&output_filename,
+                       &algoname,
+                       &edges,
+                       &adjacency_list,
+                       &bidir_shortest_dists,
+                       bidir_num_distances,
+                       bidir_mean_distance,
+                       bidir_std_dev)
+        .expect("Failed to write Bidirectional BFS output!");
+
+    //  ------ Centrality Analysis ------
+
+    println!("\n\n--> Centrality Analysis (Brandes' Betweenness / Closeness)\n");
+
+    // Brandes' betweenness is O(V * (V + E)); sample the same number of
+    // nodes the other sections use instead of ranking the whole graph so
+    // this section doesn't dwarf the rest of the driver's runtime.
+    let centrality_sample = graph_algos::sample_nodes(&adjacency_list, count);
+
+    let centrality_start = Instant::now();
+    let rankings = rank_by_centrality(&adjacency_list, &centrality_sample);
+    let centrality_elapsed = centrality_start.elapsed();
+
+    println!("Top 10 developers by betweenness centrality (the network's biggest \"brokers\"):");
+    for score in rankings.iter().take(10) {
+        println!(
+            "  Node {}: betweenness = {:.6}, closeness = {:.6}",
+            score.node, score.betweenness, score.closeness
+        );
+    }
+    println!("Elapsed Time: {:?}", centrality_elapsed);
+
+    let output_filename = "Centrality.txt";
+    create_centrality_output_file(&output_filename, &rankings)
+        .expect("Failed to write Centrality output!");
+
+    //  ------ Yen's Algorithm (k shortest alternate routes) ------
+
+    println!("\n\n--> Alternate Routes (Yen's k Shortest Paths)\n");
+
+    let (route_source, route_target) = edges[0];
+    let k = 3;
+    let alt_routes = k_shortest_paths(&weighted_adj_list, route_source, route_target, k);
+
+    println!("Top {} alternate routes between {} and {}:", k, route_source, route_target);
+    for (rank, route) in alt_routes.iter().enumerate() {
+        let route_str = route.iter().map(|n| n.to_string()).collect::<Vec<String>>().join(" -> ");
+        println!("  Route {}: {}", rank + 1, route_str);
+    }
+
+    //  ------ Incremental Dijkstra (what-if edge insertion) ------
+
+    println!("\n\n--> Incremental Dijkstra (What-If Edge Insertion)\n");
+
+    let whatif_source = edges[0].0;
+    let whatif_target = edges[edges.len() - 1].1;
+    let mut dynamic_paths = DynamicShortestPaths::new(weighted_adj_list.clone(), whatif_source);
+
+    let dist_before = *dynamic_paths.dists().get(&whatif_target).unwrap_or(&u32::MAX);
+    println!(
+        "Distance from {} to {} before new connection: {}",
+        dynamic_paths.source(), whatif_target, dist_before
+    );
+
+    dynamic_paths.add_edge(whatif_source, whatif_target, 1);
+
+    let dist_after = *dynamic_paths.dists().get(&whatif_target).unwrap_or(&u32::MAX);
+    println!("Distance from {} to {} after adding a direct connection: {}", whatif_source, whatif_target, dist_after);
+
     //time_test(&adjacency_list);
 
     Ok(())
@@ -259,7 +351,29 @@ fn create_output_file(filename: &str,
 
     writeln!(file, "\n------ All Shortest Distances ------")?;
     for d in shortest_dists {
-        writeln!(file, "Shortest distance between {} and {} is {}", d.node_1, d.node_2, d.distance)?;
+        let path_str = d.path.iter().map(|n| n.to_string()).collect::<Vec<String>>().join(" -> ");
+        writeln!(file, "Shortest distance between {} and {} is {} ({})", d.node_1, d.node_2, d.distance, path_str)?;
+    }
+    drop(file);
+    Ok(())
+}
+
+// Helper function to write the centrality ranking (most central "brokers"
+// first) out to a txt file.
+fn create_centrality_output_file(filename: &str, rankings: &Vec<CentralityScore>) -> Result<()> {
+    let mut file = File::create(filename)?;
+    writeln!(file, "Algorithm: Brandes' Betweenness / Closeness Centrality\n")?;
+
+    writeln!(file, "Run Statistics:")?;
+    writeln!(file, "  Number of nodes ranked: {}\n", rankings.len())?;
+
+    writeln!(file, "\n------ Centrality Ranking (descending betweenness) ------")?;
+    for score in rankings {
+        writeln!(
+            file,
+            "Node {}: betweenness = {:.6}, closeness = {:.6}",
+            score.node, score.betweenness, score.closeness
+        )?;
     }
     drop(file);
     Ok(())